@@ -0,0 +1,158 @@
+//! Compact, bincode-safe binary snapshot format for `Orderbook`.
+//!
+//! `rust_decimal::Decimal`'s `Serialize`/`Deserialize` impls work fine
+//! against self-describing formats like JSON, but `Decimal`'s
+//! deserializer calls `deserialize_any`, which bincode doesn't support, and
+//! deserialization fails with `DeserializeAnyNotSupported`. This module mirrors
+//! `Orderbook`/`PriceLevel` with a plain-integer stand-in for every
+//! `Decimal` field — its `(i128 mantissa, u32 scale, sign)` representation
+//! — so the whole tree round-trips through bincode. This is what lets live
+//! books be recorded to disk and replayed deterministically for
+//! backtesting the arbitrage detector.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::types::{Orderbook, PriceLevel};
+
+#[derive(Debug, Error)]
+pub enum CodecError {
+    #[error("bincode encode/decode error: {0}")]
+    Bincode(#[from] bincode::Error),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CompactDecimal {
+    /// Unsigned coefficient of the decimal.
+    mantissa: i128,
+    scale: u32,
+    negative: bool,
+}
+
+impl From<Decimal> for CompactDecimal {
+    fn from(value: Decimal) -> Self {
+        Self {
+            mantissa: value.mantissa().unsigned_abs() as i128,
+            scale: value.scale(),
+            negative: value.is_sign_negative(),
+        }
+    }
+}
+
+impl From<CompactDecimal> for Decimal {
+    fn from(value: CompactDecimal) -> Self {
+        let mut decimal = Decimal::from_i128_with_scale(value.mantissa, value.scale);
+        decimal.set_sign_negative(value.negative);
+        decimal
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CompactPriceLevel {
+    price: CompactDecimal,
+    quantity: CompactDecimal,
+    timestamp: u64,
+}
+
+impl From<&PriceLevel> for CompactPriceLevel {
+    fn from(level: &PriceLevel) -> Self {
+        Self {
+            price: level.price.into(),
+            quantity: level.quantity.into(),
+            timestamp: level.timestamp,
+        }
+    }
+}
+
+impl From<CompactPriceLevel> for PriceLevel {
+    fn from(level: CompactPriceLevel) -> Self {
+        Self {
+            price: level.price.into(),
+            quantity: level.quantity.into(),
+            timestamp: level.timestamp,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CompactOrderbook {
+    exchange: String,
+    symbol: String,
+    bids: Vec<CompactPriceLevel>,
+    asks: Vec<CompactPriceLevel>,
+    timestamp: u64,
+}
+
+impl From<&Orderbook> for CompactOrderbook {
+    fn from(book: &Orderbook) -> Self {
+        Self {
+            exchange: book.exchange.clone(),
+            symbol: book.symbol.clone(),
+            bids: book.bids.iter().map(CompactPriceLevel::from).collect(),
+            asks: book.asks.iter().map(CompactPriceLevel::from).collect(),
+            timestamp: book.timestamp,
+        }
+    }
+}
+
+impl From<CompactOrderbook> for Orderbook {
+    fn from(book: CompactOrderbook) -> Self {
+        Self {
+            exchange: book.exchange,
+            symbol: book.symbol,
+            bids: book.bids.into_iter().map(PriceLevel::from).collect(),
+            asks: book.asks.into_iter().map(PriceLevel::from).collect(),
+            timestamp: book.timestamp,
+        }
+    }
+}
+
+impl Orderbook {
+    /// Encode this book into the compact bincode-safe binary format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, CodecError> {
+        Ok(bincode::serialize(&CompactOrderbook::from(self))?)
+    }
+
+    /// Decode a book previously written by [`Orderbook::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Orderbook, CodecError> {
+        let compact: CompactOrderbook = bincode::deserialize(bytes)?;
+        Ok(compact.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn round_trips_through_bincode() {
+        let book = Orderbook {
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            bids: vec![PriceLevel {
+                price: Decimal::from_str("42000.125").unwrap(),
+                quantity: Decimal::from_str("0.5").unwrap(),
+                timestamp: 1_700_000_000,
+            }],
+            asks: vec![PriceLevel {
+                price: Decimal::from_str("-1.50").unwrap(),
+                quantity: Decimal::from_str("10").unwrap(),
+                timestamp: 1_700_000_001,
+            }],
+            timestamp: 1_700_000_002,
+        };
+
+        let bytes = book.to_bytes().expect("encode");
+        let decoded = Orderbook::from_bytes(&bytes).expect("decode");
+
+        assert_eq!(decoded.exchange, book.exchange);
+        assert_eq!(decoded.symbol, book.symbol);
+        assert_eq!(decoded.timestamp, book.timestamp);
+        assert_eq!(decoded.bids[0].price, book.bids[0].price);
+        assert_eq!(decoded.bids[0].quantity, book.bids[0].quantity);
+        assert_eq!(decoded.asks[0].price, book.asks[0].price);
+        assert_eq!(decoded.asks[0].quantity, book.asks[0].quantity);
+    }
+}
@@ -0,0 +1,326 @@
+//! Triangular-arbitrage detection via negative-cycle search over a currency graph.
+//!
+//! Each tradable `Orderbook` becomes up to two directed edges in a graph whose
+//! nodes are assets: buying the base asset with the quote asset, and selling
+//! the base asset for the quote asset. An edge's weight is `-ln(rate)` where
+//! `rate` is the effective conversion rate after fees, so a cycle whose legs
+//! multiply out to more than 1x capital is exactly a negative-weight cycle.
+//! We find one with Bellman-Ford run from a single super-source.
+
+use std::collections::HashMap;
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+use crate::types::Orderbook;
+
+/// Direction traded on a `Market`'s orderbook for a given edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// Buy the base asset with the quote asset, filling against `asks`.
+    Buy,
+    /// Sell the base asset for the quote asset, filling against `bids`.
+    Sell,
+}
+
+/// A tradable orderbook annotated with the two assets it converts between.
+///
+/// `Orderbook::symbol` has no universal base/quote convention across
+/// exchanges, so callers supply the asset pair explicitly.
+pub struct Market<'a> {
+    pub base: String,
+    pub quote: String,
+    pub exchange: String,
+    pub orderbook: &'a Orderbook,
+    /// Multiplier applied to the raw top-of-book rate to account for taker
+    /// fees, e.g. `0.999` for a 10bps fee.
+    pub fee_multiplier: Decimal,
+}
+
+/// One leg of a detected arbitrage cycle.
+#[derive(Debug, Clone)]
+pub struct Leg {
+    pub from_asset: String,
+    pub to_asset: String,
+    pub exchange: String,
+    pub symbol: String,
+    pub side: Side,
+    /// Effective conversion rate for this leg, fee-adjusted: `1 / best ask`
+    /// for a buy, `best bid` for a sell.
+    pub rate: Decimal,
+}
+
+/// A profitable trading cycle: following `legs` in order multiplies starting
+/// capital by `gross_multiplier`.
+#[derive(Debug, Clone)]
+pub struct ArbitrageOpportunity {
+    pub legs: Vec<Leg>,
+    pub gross_multiplier: Decimal,
+}
+
+struct Edge {
+    to: usize,
+    weight: f64,
+    rate: Decimal,
+    exchange: String,
+    symbol: String,
+    side: Side,
+}
+
+/// Build the currency graph from a set of markets and search it for a
+/// negative-weight cycle, i.e. a sequence of trades whose rate product
+/// exceeds 1. Returns `None` if no profitable cycle exists.
+pub fn detect_arbitrage(markets: &[Market]) -> Option<ArbitrageOpportunity> {
+    let mut assets: Vec<String> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let node_of = |asset: &str, assets: &mut Vec<String>, index: &mut HashMap<String, usize>| -> usize {
+        if let Some(&i) = index.get(asset) {
+            return i;
+        }
+        let i = assets.len();
+        assets.push(asset.to_string());
+        index.insert(asset.to_string(), i);
+        i
+    };
+
+    let mut edges: Vec<Edge> = Vec::new();
+    let mut from_node: Vec<usize> = Vec::new();
+
+    for market in markets {
+        let base = node_of(&market.base, &mut assets, &mut index);
+        let quote = node_of(&market.quote, &mut assets, &mut index);
+
+        if let Some(best_ask) = market.orderbook.asks.first() {
+            if !best_ask.price.is_zero() {
+                let rate = (Decimal::ONE / best_ask.price) * market.fee_multiplier;
+                if let Some(weight) = effective_weight(rate) {
+                    from_node.push(quote);
+                    edges.push(Edge {
+                        to: base,
+                        weight,
+                        rate,
+                        exchange: market.exchange.clone(),
+                        symbol: market.orderbook.symbol.clone(),
+                        side: Side::Buy,
+                    });
+                }
+            }
+        }
+
+        if let Some(best_bid) = market.orderbook.bids.first() {
+            let rate = best_bid.price * market.fee_multiplier;
+            if let Some(weight) = effective_weight(rate) {
+                from_node.push(base);
+                edges.push(Edge {
+                    to: quote,
+                    weight,
+                    rate,
+                    exchange: market.exchange.clone(),
+                    symbol: market.orderbook.symbol.clone(),
+                    side: Side::Sell,
+                });
+            }
+        }
+    }
+
+    let n = assets.len();
+    if n == 0 {
+        return None;
+    }
+
+    // Super-source with zero-weight edges to every asset, so a single
+    // Bellman-Ford run can discover a negative cycle reachable from anywhere.
+    const SOURCE: f64 = 0.0;
+    let mut dist = vec![SOURCE; n];
+    let mut pred: Vec<Option<usize>> = vec![None; n];
+    let mut pred_edge: Vec<Option<usize>> = vec![None; n];
+
+    for _ in 0..n {
+        let mut relaxed_any = false;
+        for (e, edge) in edges.iter().enumerate() {
+            let u = from_node[e];
+            let candidate = dist[u] + edge.weight;
+            if candidate < dist[edge.to] - 1e-12 {
+                dist[edge.to] = candidate;
+                pred[edge.to] = Some(u);
+                pred_edge[edge.to] = Some(e);
+                relaxed_any = true;
+            }
+        }
+        if !relaxed_any {
+            return None;
+        }
+    }
+
+    // One more pass: any node that still relaxes lies on (or is reachable
+    // from) a negative cycle.
+    let mut cycle_node = None;
+    for (e, edge) in edges.iter().enumerate() {
+        let u = from_node[e];
+        let candidate = dist[u] + edge.weight;
+        if candidate < dist[edge.to] - 1e-12 {
+            cycle_node = Some(edge.to);
+            break;
+        }
+    }
+
+    let mut node = cycle_node?;
+    // Walk predecessors V times, where V = n + 1 counts the n real assets
+    // plus the virtual super-source, to guarantee landing inside the cycle
+    // rather than merely on a path leading into it.
+    for _ in 0..n + 1 {
+        node = pred[node]?;
+    }
+
+    let start = node;
+    let mut cycle_edges = Vec::new();
+    loop {
+        let e = pred_edge[node]?;
+        cycle_edges.push(e);
+        node = pred[node]?;
+        if node == start {
+            break;
+        }
+    }
+    cycle_edges.reverse();
+
+    let mut gross_multiplier = Decimal::ONE;
+    let mut legs = Vec::with_capacity(cycle_edges.len());
+    for &e in &cycle_edges {
+        let edge = &edges[e];
+        let u = from_node[e];
+        gross_multiplier *= edge.rate;
+        legs.push(Leg {
+            from_asset: assets[u].clone(),
+            to_asset: assets[edge.to].clone(),
+            exchange: edge.exchange.clone(),
+            symbol: edge.symbol.clone(),
+            side: edge.side,
+            rate: edge.rate,
+        });
+    }
+
+    Some(ArbitrageOpportunity {
+        legs,
+        gross_multiplier,
+    })
+}
+
+/// `-ln(rate)` as an `f64` edge weight, or `None` if `rate` isn't usable
+/// (non-positive, or doesn't fit in an `f64`).
+fn effective_weight(rate: Decimal) -> Option<f64> {
+    if rate <= Decimal::ZERO {
+        return None;
+    }
+    let rate_f64 = rate.to_f64()?;
+    if rate_f64 <= 0.0 || !rate_f64.is_finite() {
+        return None;
+    }
+    Some(-rate_f64.ln())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PriceLevel;
+    use std::str::FromStr;
+
+    fn level(price: &str, quantity: &str) -> PriceLevel {
+        PriceLevel {
+            price: Decimal::from_str(price).unwrap(),
+            quantity: Decimal::from_str(quantity).unwrap(),
+            timestamp: 0,
+        }
+    }
+
+    fn book(ask: &str) -> Orderbook {
+        Orderbook {
+            exchange: "test".to_string(),
+            symbol: "TEST".to_string(),
+            bids: Vec::new(),
+            asks: vec![level(ask, "1000")],
+            timestamp: 0,
+        }
+    }
+
+    fn market<'a>(base: &str, quote: &str, orderbook: &'a Orderbook) -> Market<'a> {
+        Market {
+            base: base.to_string(),
+            quote: quote.to_string(),
+            exchange: "test".to_string(),
+            orderbook,
+            fee_multiplier: Decimal::ONE,
+        }
+    }
+
+    #[test]
+    fn finds_a_profitable_triangle() {
+        // A -> B -> C -> A, each leg buying at a rate of 1/0.99 (i.e. the
+        // quote asset buys slightly more base than 1:1), so the product of
+        // the three legs' rates is (1/0.99)^3 > 1.
+        let ab = book("0.99");
+        let bc = book("0.99");
+        let ca = book("0.99");
+        let markets = vec![
+            market("B", "A", &ab),
+            market("C", "B", &bc),
+            market("A", "C", &ca),
+        ];
+
+        let opportunity = detect_arbitrage(&markets).expect("expected a profitable cycle");
+
+        assert_eq!(opportunity.legs.len(), 3);
+        assert!(opportunity.gross_multiplier > Decimal::ONE);
+        for leg in &opportunity.legs {
+            assert_eq!(leg.side, Side::Buy);
+        }
+    }
+
+    #[test]
+    fn no_cycle_returns_none() {
+        // A single market with only asks yields a single edge, which can
+        // never form a cycle on its own.
+        let ab = book("2.0");
+        let markets = vec![market("B", "A", &ab)];
+
+        assert!(detect_arbitrage(&markets).is_none());
+    }
+
+    #[test]
+    fn finds_cycle_past_a_long_non_cycle_prefix_chain() {
+        // A chain of markets P0 -> P1 -> ... -> Pk -> X feeds into an
+        // unrelated profitable triangle X -> Y -> Z -> X. The prefix is not
+        // itself part of any cycle, but it lengthens the predecessor chain
+        // Bellman-Ford builds before the cycle is ever reached, exercising
+        // the `n + 1`-hop walk-back bound against more than a 3-node graph.
+        let prefix_books: Vec<Orderbook> = (0..5).map(|_| book("0.99")).collect();
+        let mut markets: Vec<Market> = Vec::new();
+        for i in 0..prefix_books.len() {
+            let from = format!("P{i}");
+            let to = if i + 1 == prefix_books.len() {
+                "X".to_string()
+            } else {
+                format!("P{}", i + 1)
+            };
+            markets.push(market(&to, &from, &prefix_books[i]));
+        }
+
+        let xy = book("0.99");
+        let yz = book("0.99");
+        let zx = book("0.99");
+        markets.push(market("Y", "X", &xy));
+        markets.push(market("Z", "Y", &yz));
+        markets.push(market("X", "Z", &zx));
+
+        let opportunity = detect_arbitrage(&markets).expect("expected a profitable cycle");
+
+        assert_eq!(opportunity.legs.len(), 3);
+        assert!(opportunity.gross_multiplier > Decimal::ONE);
+        let assets_touched: std::collections::HashSet<&str> = opportunity
+            .legs
+            .iter()
+            .flat_map(|leg| [leg.from_asset.as_str(), leg.to_asset.as_str()])
+            .collect();
+        assert_eq!(assets_touched, ["X", "Y", "Z"].into_iter().collect());
+    }
+}
@@ -0,0 +1,281 @@
+//! Order models for the execution layer.
+//!
+//! An arbitrage strategy needs more than plain immediate fills to protect
+//! its legs: stop-loss and stop-limit orders to bail out of a partially
+//! filled cycle, reduce-only orders to unwind without flipping a position,
+//! and `close_position` to flatten regardless of size.
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderType {
+    Market,
+    Limit,
+    StopMarket,
+    StopLimit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeInForce {
+    GoodTilCancel,
+    ImmediateOrCancel,
+    FillOrKill,
+    GoodTilCrossing,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum OrderValidationError {
+    #[error("quantity must be positive")]
+    NonPositiveQuantity,
+    #[error("market orders must not specify price or stop_price")]
+    MarketOrderHasPrice,
+    #[error("limit orders require a price")]
+    MissingLimitPrice,
+    #[error("stop-market orders require a stop_price")]
+    MissingStopPrice,
+    #[error("stop-limit orders require both stop_price and price")]
+    MissingStopLimitFields,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Order {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub quantity: Decimal,
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
+    pub time_in_force: TimeInForce,
+    pub reduce_only: bool,
+    pub close_position: bool,
+}
+
+impl Order {
+    fn validate(&self) -> Result<(), OrderValidationError> {
+        if self.quantity <= Decimal::ZERO {
+            return Err(OrderValidationError::NonPositiveQuantity);
+        }
+        match self.order_type {
+            OrderType::Market => {
+                if self.price.is_some() || self.stop_price.is_some() {
+                    return Err(OrderValidationError::MarketOrderHasPrice);
+                }
+            }
+            OrderType::Limit => {
+                if self.price.is_none() {
+                    return Err(OrderValidationError::MissingLimitPrice);
+                }
+            }
+            OrderType::StopMarket => {
+                if self.stop_price.is_none() {
+                    return Err(OrderValidationError::MissingStopPrice);
+                }
+            }
+            OrderType::StopLimit => {
+                if self.price.is_none() || self.stop_price.is_none() {
+                    return Err(OrderValidationError::MissingStopLimitFields);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`Order`]. `build` validates that the fields present match
+/// what `order_type` requires (e.g. a stop-limit needs both `stop_price`
+/// and `price`; a market order needs neither).
+pub struct OrderBuilder {
+    symbol: String,
+    side: OrderSide,
+    order_type: OrderType,
+    quantity: Decimal,
+    price: Option<Decimal>,
+    stop_price: Option<Decimal>,
+    time_in_force: TimeInForce,
+    reduce_only: bool,
+    close_position: bool,
+}
+
+impl OrderBuilder {
+    pub fn new(
+        symbol: impl Into<String>,
+        side: OrderSide,
+        order_type: OrderType,
+        quantity: Decimal,
+    ) -> Self {
+        Self {
+            symbol: symbol.into(),
+            side,
+            order_type,
+            quantity,
+            price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::GoodTilCancel,
+            reduce_only: false,
+            close_position: false,
+        }
+    }
+
+    pub fn price(mut self, price: Decimal) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn stop_price(mut self, stop_price: Decimal) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = time_in_force;
+        self
+    }
+
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = reduce_only;
+        self
+    }
+
+    pub fn close_position(mut self, close_position: bool) -> Self {
+        self.close_position = close_position;
+        self
+    }
+
+    pub fn build(self) -> Result<Order, OrderValidationError> {
+        let order = Order {
+            symbol: self.symbol,
+            side: self.side,
+            order_type: self.order_type,
+            quantity: self.quantity,
+            price: self.price,
+            stop_price: self.stop_price,
+            time_in_force: self.time_in_force,
+            reduce_only: self.reduce_only,
+            close_position: self.close_position,
+        };
+        order.validate()?;
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn qty() -> Decimal {
+        Decimal::from_str("1").unwrap()
+    }
+
+    fn price() -> Decimal {
+        Decimal::from_str("100").unwrap()
+    }
+
+    #[test]
+    fn rejects_non_positive_quantity() {
+        let err = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Market, Decimal::ZERO)
+            .build()
+            .unwrap_err();
+        assert_eq!(err, OrderValidationError::NonPositiveQuantity);
+    }
+
+    #[test]
+    fn market_order_rejects_price_or_stop_price() {
+        let err = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Market, qty())
+            .price(price())
+            .build()
+            .unwrap_err();
+        assert_eq!(err, OrderValidationError::MarketOrderHasPrice);
+
+        let err = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Market, qty())
+            .stop_price(price())
+            .build()
+            .unwrap_err();
+        assert_eq!(err, OrderValidationError::MarketOrderHasPrice);
+    }
+
+    #[test]
+    fn market_order_without_price_or_stop_price_is_valid() {
+        let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Market, qty())
+            .build()
+            .unwrap();
+        assert_eq!(order.order_type, OrderType::Market);
+    }
+
+    #[test]
+    fn limit_order_requires_price() {
+        let err = OrderBuilder::new("BTCUSDT", OrderSide::Sell, OrderType::Limit, qty())
+            .build()
+            .unwrap_err();
+        assert_eq!(err, OrderValidationError::MissingLimitPrice);
+
+        let order = OrderBuilder::new("BTCUSDT", OrderSide::Sell, OrderType::Limit, qty())
+            .price(price())
+            .build()
+            .unwrap();
+        assert_eq!(order.price, Some(price()));
+    }
+
+    #[test]
+    fn stop_market_order_requires_stop_price() {
+        let err = OrderBuilder::new("BTCUSDT", OrderSide::Sell, OrderType::StopMarket, qty())
+            .build()
+            .unwrap_err();
+        assert_eq!(err, OrderValidationError::MissingStopPrice);
+
+        let order = OrderBuilder::new("BTCUSDT", OrderSide::Sell, OrderType::StopMarket, qty())
+            .stop_price(price())
+            .build()
+            .unwrap();
+        assert_eq!(order.stop_price, Some(price()));
+    }
+
+    #[test]
+    fn stop_limit_order_requires_both_stop_price_and_price() {
+        let err = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::StopLimit, qty())
+            .build()
+            .unwrap_err();
+        assert_eq!(err, OrderValidationError::MissingStopLimitFields);
+
+        let err = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::StopLimit, qty())
+            .price(price())
+            .build()
+            .unwrap_err();
+        assert_eq!(err, OrderValidationError::MissingStopLimitFields);
+
+        let err = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::StopLimit, qty())
+            .stop_price(price())
+            .build()
+            .unwrap_err();
+        assert_eq!(err, OrderValidationError::MissingStopLimitFields);
+
+        let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::StopLimit, qty())
+            .price(price())
+            .stop_price(price())
+            .build()
+            .unwrap();
+        assert_eq!(order.price, Some(price()));
+        assert_eq!(order.stop_price, Some(price()));
+    }
+
+    #[test]
+    fn builder_carries_through_optional_flags() {
+        let order = OrderBuilder::new("BTCUSDT", OrderSide::Sell, OrderType::Market, qty())
+            .time_in_force(TimeInForce::ImmediateOrCancel)
+            .reduce_only(true)
+            .close_position(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(order.time_in_force, TimeInForce::ImmediateOrCancel);
+        assert!(order.reduce_only);
+        assert!(order.close_position);
+    }
+}
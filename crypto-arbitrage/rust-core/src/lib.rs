@@ -1,10 +1,22 @@
 use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
 
 pub mod types;
 pub mod orderbook;
 pub mod execution;
+pub mod detect;
+pub mod stream;
+pub mod python;
+pub mod codec;
 
 #[pymodule]
 fn crypto_arbitrage_core(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<python::PyPriceLevel>()?;
+    m.add_class::<python::PyOrderbook>()?;
+    m.add_class::<python::PyLeg>()?;
+    m.add_class::<python::PyArbitrageOpportunity>()?;
+    m.add_class::<python::PyWalkResult>()?;
+    m.add_function(wrap_pyfunction!(python::detect_arbitrage, m)?)?;
+    m.add_function(wrap_pyfunction!(python::walk_for_notional, m)?)?;
     Ok(())
 }
@@ -0,0 +1,485 @@
+//! `Orderbook` behavior: loading exchange trading rules and normalizing
+//! books to a venue's price/quantity precision before an order is sent.
+//!
+//! Raw arbitrage math produces prices and quantities the venue will reject
+//! outright (wrong tick size, below minimum notional, ...). Normalizing
+//! against the venue's published rules first avoids wasted round-trips.
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::types::{ExchangeRules, Orderbook, PriceLevel};
+
+#[derive(Debug, Error)]
+pub enum RulesError {
+    #[error("failed to parse exchange rules: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum OrderValidationError {
+    #[error("quantity {quantity} is below the minimum {min_qty}")]
+    BelowMinQty { quantity: Decimal, min_qty: Decimal },
+    #[error("quantity {quantity} is above the maximum {max_qty}")]
+    AboveMaxQty { quantity: Decimal, max_qty: Decimal },
+    #[error("notional {notional} is below the minimum {min_notional}")]
+    BelowMinNotional {
+        notional: Decimal,
+        min_notional: Decimal,
+    },
+}
+
+impl ExchangeRules {
+    /// Parse a JSON array of per-symbol rules, as returned by a typical
+    /// exchange-info endpoint.
+    pub fn load_all(json: &str) -> Result<Vec<ExchangeRules>, RulesError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Round `price` to this symbol's tick size, rounding down for a buy
+    /// (never overpay) or up for a sell (never undersell).
+    pub fn snap_price(&self, price: Decimal, round_up: bool) -> Decimal {
+        snap_to_step(price, self.tick_size, round_up).round_dp(self.price_scale)
+    }
+
+    /// Round `quantity` down to this symbol's step size; orders can never
+    /// fill more than the book offers, so quantity always rounds down.
+    pub fn snap_quantity(&self, quantity: Decimal) -> Decimal {
+        snap_to_step(quantity, self.step_size, false).round_dp(self.quantity_scale)
+    }
+
+    /// Reject an order that violates min/max quantity or min-notional
+    /// constraints. Does not check step/tick alignment; call
+    /// [`ExchangeRules::snap_price`]/[`ExchangeRules::snap_quantity`] first.
+    pub fn validate_order(
+        &self,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Result<(), OrderValidationError> {
+        if quantity < self.min_qty {
+            return Err(OrderValidationError::BelowMinQty {
+                quantity,
+                min_qty: self.min_qty,
+            });
+        }
+        if quantity > self.max_qty {
+            return Err(OrderValidationError::AboveMaxQty {
+                quantity,
+                max_qty: self.max_qty,
+            });
+        }
+        let notional = price * quantity;
+        if notional < self.min_notional {
+            return Err(OrderValidationError::BelowMinNotional {
+                notional,
+                min_notional: self.min_notional,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Round `value` to the nearest multiple of `step` (down, or up if
+/// `round_up`). A non-positive `step` is treated as "no constraint".
+fn snap_to_step(value: Decimal, step: Decimal, round_up: bool) -> Decimal {
+    if step <= Decimal::ZERO {
+        return value;
+    }
+    let units = (value / step).trunc();
+    let snapped = units * step;
+    if round_up && snapped < value {
+        snapped + step
+    } else {
+        snapped
+    }
+}
+
+impl PriceLevel {
+    /// Snap this level's price and quantity to `rules`' precision.
+    pub fn normalize(&self, rules: &ExchangeRules, round_price_up: bool) -> PriceLevel {
+        PriceLevel {
+            price: rules.snap_price(self.price, round_price_up),
+            quantity: rules.snap_quantity(self.quantity),
+            timestamp: self.timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod rules_tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn rules() -> ExchangeRules {
+        ExchangeRules {
+            exchange: "test".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            price_scale: 2,
+            quantity_scale: 6,
+            tick_size: Decimal::from_str("0.01").unwrap(),
+            step_size: Decimal::from_str("0.001").unwrap(),
+            min_notional: Decimal::from_str("10").unwrap(),
+            min_qty: Decimal::from_str("0.0001").unwrap(),
+            max_qty: Decimal::from_str("100").unwrap(),
+        }
+    }
+
+    #[test]
+    fn snap_price_rounds_down_for_buys() {
+        let rules = rules();
+        let snapped = rules.snap_price(Decimal::from_str("100.127").unwrap(), false);
+        assert_eq!(snapped, Decimal::from_str("100.12").unwrap());
+    }
+
+    #[test]
+    fn load_all_parses_an_exchange_info_shaped_array() {
+        let json = r#"[
+            {
+                "exchange": "binance",
+                "symbol": "BTCUSDT",
+                "price_scale": 2,
+                "quantity_scale": 6,
+                "tick_size": "0.01",
+                "step_size": "0.000001",
+                "min_notional": "10",
+                "min_qty": "0.00001",
+                "max_qty": "9000"
+            },
+            {
+                "exchange": "binance",
+                "symbol": "ETHUSDT",
+                "price_scale": 2,
+                "quantity_scale": 5,
+                "tick_size": "0.01",
+                "step_size": "0.00001",
+                "min_notional": "10",
+                "min_qty": "0.0001",
+                "max_qty": "90000"
+            }
+        ]"#;
+
+        let parsed = ExchangeRules::load_all(json).expect("valid exchange-info JSON");
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].exchange, "binance");
+        assert_eq!(parsed[0].symbol, "BTCUSDT");
+        assert_eq!(parsed[0].price_scale, 2);
+        assert_eq!(parsed[0].tick_size, Decimal::from_str("0.01").unwrap());
+        assert_eq!(parsed[1].symbol, "ETHUSDT");
+        assert_eq!(parsed[1].max_qty, Decimal::from_str("90000").unwrap());
+    }
+
+    #[test]
+    fn load_all_rejects_malformed_json() {
+        assert!(ExchangeRules::load_all("not json").is_err());
+    }
+
+    #[test]
+    fn snap_price_rounds_up_for_sells() {
+        let rules = rules();
+        let snapped = rules.snap_price(Decimal::from_str("100.121").unwrap(), true);
+        assert_eq!(snapped, Decimal::from_str("100.13").unwrap());
+    }
+
+    #[test]
+    fn snap_price_exact_multiple_is_unchanged_either_direction() {
+        let rules = rules();
+        let exact = Decimal::from_str("100.10").unwrap();
+        assert_eq!(rules.snap_price(exact, false), exact);
+        assert_eq!(rules.snap_price(exact, true), exact);
+    }
+
+    #[test]
+    fn snap_quantity_always_rounds_down() {
+        let rules = rules();
+        let snapped = rules.snap_quantity(Decimal::from_str("1.2349").unwrap());
+        assert_eq!(snapped, Decimal::from_str("1.234").unwrap());
+    }
+
+    #[test]
+    fn snap_to_step_treats_non_positive_step_as_unconstrained() {
+        let value = Decimal::from_str("3.14159").unwrap();
+        assert_eq!(snap_to_step(value, Decimal::ZERO, false), value);
+        assert_eq!(
+            snap_to_step(value, Decimal::from_str("-1").unwrap(), true),
+            value
+        );
+    }
+
+    #[test]
+    fn validate_order_rejects_below_min_qty() {
+        let rules = rules();
+        let err = rules
+            .validate_order(Decimal::from_str("100").unwrap(), Decimal::from_str("0.00001").unwrap())
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OrderValidationError::BelowMinQty {
+                quantity: Decimal::from_str("0.00001").unwrap(),
+                min_qty: rules.min_qty,
+            }
+        );
+    }
+
+    #[test]
+    fn validate_order_rejects_above_max_qty() {
+        let rules = rules();
+        let err = rules
+            .validate_order(Decimal::from_str("100").unwrap(), Decimal::from_str("101").unwrap())
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OrderValidationError::AboveMaxQty {
+                quantity: Decimal::from_str("101").unwrap(),
+                max_qty: rules.max_qty,
+            }
+        );
+    }
+
+    #[test]
+    fn validate_order_rejects_notional_just_below_the_boundary() {
+        let rules = rules();
+        // Exactly at min_notional is allowed; a hair below is not.
+        let price = Decimal::from_str("10").unwrap();
+        assert!(rules.validate_order(price, Decimal::ONE).is_ok());
+
+        let err = rules
+            .validate_order(price, Decimal::from_str("0.9999").unwrap())
+            .unwrap_err();
+        assert_eq!(
+            err,
+            OrderValidationError::BelowMinNotional {
+                notional: Decimal::from_str("9.999").unwrap(),
+                min_notional: rules.min_notional,
+            }
+        );
+    }
+
+    #[test]
+    fn validate_order_accepts_within_all_bounds() {
+        let rules = rules();
+        assert!(rules
+            .validate_order(Decimal::from_str("100").unwrap(), Decimal::ONE)
+            .is_ok());
+    }
+}
+
+impl Orderbook {
+    /// Return a copy of this book with every level snapped to `rules`'
+    /// precision. Bids round their price down (a resting bid can't offer
+    /// more than it states) and asks round their price up, matching how
+    /// each side would actually be accepted by the venue.
+    pub fn normalize(&self, rules: &ExchangeRules) -> Orderbook {
+        Orderbook {
+            exchange: self.exchange.clone(),
+            symbol: self.symbol.clone(),
+            bids: self
+                .bids
+                .iter()
+                .map(|level| level.normalize(rules, false))
+                .collect(),
+            asks: self
+                .asks
+                .iter()
+                .map(|level| level.normalize(rules, true))
+                .collect(),
+            timestamp: self.timestamp,
+        }
+    }
+}
+
+/// Which side of the book a walk fills against: `Buy` consumes `asks`,
+/// `Sell` consumes `bids`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillSide {
+    Buy,
+    Sell,
+}
+
+/// Result of walking the book for a target notional.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalkResult {
+    /// Base-asset quantity that could actually be filled.
+    pub filled_quantity: Decimal,
+    /// Volume-weighted average price of the fill (zero if nothing filled).
+    pub vwap: Decimal,
+    /// Quote-asset notional that could not be filled, either because the
+    /// book ran out of depth or because `max_avg_price` was reached.
+    pub leftover_notional: Decimal,
+}
+
+impl Orderbook {
+    /// Walk `side`'s levels, filling up to `target_notional` of quote
+    /// currency. If `max_avg_price` is given, stop before consuming any
+    /// level priced worse than it (asks above it for a buy, bids below it
+    /// for a sell), treating the rest of `target_notional` as leftover.
+    /// All accumulation uses `Decimal` to avoid float rounding error.
+    pub fn walk_for_notional(
+        &self,
+        side: FillSide,
+        target_notional: Decimal,
+        max_avg_price: Option<Decimal>,
+    ) -> WalkResult {
+        let levels: &[PriceLevel] = match side {
+            FillSide::Buy => &self.asks,
+            FillSide::Sell => &self.bids,
+        };
+
+        let mut remaining_notional = target_notional;
+        let mut filled_quantity = Decimal::ZERO;
+        let mut spent_notional = Decimal::ZERO;
+
+        for level in levels {
+            if remaining_notional <= Decimal::ZERO || level.price.is_zero() {
+                break;
+            }
+            if let Some(max_avg) = max_avg_price {
+                let breaches = match side {
+                    FillSide::Buy => level.price > max_avg,
+                    FillSide::Sell => level.price < max_avg,
+                };
+                if breaches {
+                    break;
+                }
+            }
+
+            let level_notional_available = level.price * level.quantity;
+            let take_notional = remaining_notional.min(level_notional_available);
+            let take_quantity = take_notional / level.price;
+
+            filled_quantity += take_quantity;
+            spent_notional += take_notional;
+            remaining_notional -= take_notional;
+        }
+
+        let vwap = if filled_quantity.is_zero() {
+            Decimal::ZERO
+        } else {
+            spent_notional / filled_quantity
+        };
+
+        WalkResult {
+            filled_quantity,
+            vwap,
+            leftover_notional: remaining_notional,
+        }
+    }
+
+    /// Largest base-asset quantity that can be bought with `available_balance`
+    /// of quote currency, at `fee_rate` (e.g. `0.001` for 10bps) and within
+    /// `max_slippage` (e.g. `0.01` for 1%) of the current best ask.
+    pub fn estimate_max_buy_quantity(
+        &self,
+        available_balance: Decimal,
+        fee_rate: Decimal,
+        max_slippage: Decimal,
+    ) -> Decimal {
+        let Some(best_ask) = self.asks.first() else {
+            return Decimal::ZERO;
+        };
+        let spendable_notional = available_balance / (Decimal::ONE + fee_rate);
+        let max_avg_price = best_ask.price * (Decimal::ONE + max_slippage);
+        self.walk_for_notional(FillSide::Buy, spendable_notional, Some(max_avg_price))
+            .filled_quantity
+    }
+}
+
+#[cfg(test)]
+mod walk_tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn d(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn level(price: &str, quantity: &str) -> PriceLevel {
+        PriceLevel {
+            price: d(price),
+            quantity: d(quantity),
+            timestamp: 0,
+        }
+    }
+
+    fn book_with_asks(asks: Vec<PriceLevel>) -> Orderbook {
+        Orderbook {
+            exchange: "test".to_string(),
+            symbol: "TEST".to_string(),
+            bids: Vec::new(),
+            asks,
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn walks_multiple_levels_for_vwap() {
+        let book = book_with_asks(vec![
+            level("100", "1"),
+            level("101", "1"),
+            level("102", "1"),
+        ]);
+
+        // 250 notional consumes the first two levels fully (201) and half
+        // of the third (49 of 102 = 49/102 base).
+        let result = book.walk_for_notional(FillSide::Buy, d("250"), None);
+
+        assert_eq!(result.filled_quantity, Decimal::ONE + Decimal::ONE + d("49") / d("102"));
+        assert_eq!(result.leftover_notional, Decimal::ZERO);
+        assert_eq!(result.vwap, d("250") / result.filled_quantity);
+    }
+
+    #[test]
+    fn stops_at_max_avg_price_and_reports_leftover() {
+        let book = book_with_asks(vec![level("100", "1"), level("110", "10")]);
+
+        // max_avg_price sits below the second level's price, so the walk
+        // must stop after the first level even though more notional remains.
+        let result = book.walk_for_notional(FillSide::Buy, d("500"), Some(d("105")));
+
+        assert_eq!(result.filled_quantity, Decimal::ONE);
+        assert_eq!(result.vwap, d("100"));
+        assert_eq!(result.leftover_notional, d("400"));
+    }
+
+    #[test]
+    fn zero_liquidity_book_fills_nothing() {
+        let book = book_with_asks(Vec::new());
+
+        let result = book.walk_for_notional(FillSide::Buy, d("500"), None);
+
+        assert_eq!(result.filled_quantity, Decimal::ZERO);
+        assert_eq!(result.vwap, Decimal::ZERO);
+        assert_eq!(result.leftover_notional, d("500"));
+    }
+
+    #[test]
+    fn zero_target_notional_fills_nothing() {
+        let book = book_with_asks(vec![level("100", "1")]);
+
+        let result = book.walk_for_notional(FillSide::Buy, Decimal::ZERO, None);
+
+        assert_eq!(result.filled_quantity, Decimal::ZERO);
+        assert_eq!(result.vwap, Decimal::ZERO);
+        assert_eq!(result.leftover_notional, Decimal::ZERO);
+    }
+
+    #[test]
+    fn estimate_max_buy_quantity_accounts_for_fee_and_slippage() {
+        let book = book_with_asks(vec![level("100", "10")]);
+
+        // 1% fee and 1% slippage allowance: spendable = 1000 / 1.01,
+        // max_avg_price = 101, so the whole top level is reachable.
+        let quantity = book.estimate_max_buy_quantity(d("1000"), d("0.01"), d("0.01"));
+
+        assert_eq!(quantity, d("1000") / d("1.01") / d("100"));
+    }
+
+    #[test]
+    fn estimate_max_buy_quantity_is_zero_with_no_asks() {
+        let book = book_with_asks(Vec::new());
+
+        let quantity = book.estimate_max_buy_quantity(d("1000"), d("0.01"), d("0.01"));
+
+        assert_eq!(quantity, Decimal::ZERO);
+    }
+}
@@ -0,0 +1,485 @@
+//! Python bindings for the arbitrage core: `PriceLevel` and `Orderbook` as
+//! `pyclass` types, plus wrappers for arbitrage detection and book-walking
+//! so a Python strategy layer can feed in books and receive opportunities.
+//!
+//! `rust_decimal::Decimal` has no native pyo3 conversion, so every crossing
+//! goes through `decimal.Decimal` on the Python side and `Decimal`'s exact
+//! string representation on the Rust side (`to_string`/`FromStr`), which
+//! preserves precision unlike a float round-trip.
+
+use std::str::FromStr;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rust_decimal::Decimal;
+
+use crate::detect::{self, ArbitrageOpportunity, Leg, Market, Side};
+use crate::orderbook::{FillSide, WalkResult};
+use crate::types::{Orderbook, PriceLevel};
+
+fn py_decimal_to_rust(value: &PyAny) -> PyResult<Decimal> {
+    let s: String = value.call_method0("__str__")?.extract()?;
+    Decimal::from_str(&s).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+fn rust_decimal_to_py(py: Python<'_>, value: Decimal) -> PyResult<PyObject> {
+    let decimal_cls = py.import("decimal")?.getattr("Decimal")?;
+    Ok(decimal_cls.call1((value.to_string(),))?.into())
+}
+
+#[pyclass(name = "PriceLevel")]
+#[derive(Clone)]
+pub struct PyPriceLevel {
+    pub(crate) inner: PriceLevel,
+}
+
+#[pymethods]
+impl PyPriceLevel {
+    #[new]
+    fn new(price: &PyAny, quantity: &PyAny, timestamp: u64) -> PyResult<Self> {
+        Ok(Self {
+            inner: PriceLevel {
+                price: py_decimal_to_rust(price)?,
+                quantity: py_decimal_to_rust(quantity)?,
+                timestamp,
+            },
+        })
+    }
+
+    #[getter]
+    fn price(&self, py: Python<'_>) -> PyResult<PyObject> {
+        rust_decimal_to_py(py, self.inner.price)
+    }
+
+    #[setter]
+    fn set_price(&mut self, price: &PyAny) -> PyResult<()> {
+        self.inner.price = py_decimal_to_rust(price)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn quantity(&self, py: Python<'_>) -> PyResult<PyObject> {
+        rust_decimal_to_py(py, self.inner.quantity)
+    }
+
+    #[setter]
+    fn set_quantity(&mut self, quantity: &PyAny) -> PyResult<()> {
+        self.inner.quantity = py_decimal_to_rust(quantity)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn timestamp(&self) -> u64 {
+        self.inner.timestamp
+    }
+
+    #[setter]
+    fn set_timestamp(&mut self, timestamp: u64) {
+        self.inner.timestamp = timestamp;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PriceLevel(price={}, quantity={}, timestamp={})",
+            self.inner.price, self.inner.quantity, self.inner.timestamp
+        )
+    }
+
+    /// Reconstruction args for pickling: plain decimal strings, accepted
+    /// by `#[new]` the same way a `decimal.Decimal` is.
+    fn __getnewargs__(&self) -> (String, String, u64) {
+        (
+            self.inner.price.to_string(),
+            self.inner.quantity.to_string(),
+            self.inner.timestamp,
+        )
+    }
+}
+
+#[pyclass(name = "Orderbook")]
+#[derive(Clone)]
+pub struct PyOrderbook {
+    pub(crate) inner: Orderbook,
+}
+
+#[pymethods]
+impl PyOrderbook {
+    #[new]
+    fn new(
+        exchange: String,
+        symbol: String,
+        bids: Vec<PyRef<PyPriceLevel>>,
+        asks: Vec<PyRef<PyPriceLevel>>,
+        timestamp: u64,
+    ) -> Self {
+        Self {
+            inner: Orderbook {
+                exchange,
+                symbol,
+                bids: bids.iter().map(|level| level.inner.clone()).collect(),
+                asks: asks.iter().map(|level| level.inner.clone()).collect(),
+                timestamp,
+            },
+        }
+    }
+
+    #[getter]
+    fn exchange(&self) -> String {
+        self.inner.exchange.clone()
+    }
+
+    #[getter]
+    fn symbol(&self) -> String {
+        self.inner.symbol.clone()
+    }
+
+    #[getter]
+    fn bids(&self) -> Vec<PyPriceLevel> {
+        self.inner
+            .bids
+            .iter()
+            .cloned()
+            .map(|inner| PyPriceLevel { inner })
+            .collect()
+    }
+
+    #[getter]
+    fn asks(&self) -> Vec<PyPriceLevel> {
+        self.inner
+            .asks
+            .iter()
+            .cloned()
+            .map(|inner| PyPriceLevel { inner })
+            .collect()
+    }
+
+    #[getter]
+    fn timestamp(&self) -> u64 {
+        self.inner.timestamp
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Orderbook(exchange={:?}, symbol={:?}, bids={}, asks={})",
+            self.inner.exchange,
+            self.inner.symbol,
+            self.inner.bids.len(),
+            self.inner.asks.len()
+        )
+    }
+
+    fn __getnewargs__(&self) -> (String, String, Vec<PyPriceLevel>, Vec<PyPriceLevel>, u64) {
+        (
+            self.inner.exchange.clone(),
+            self.inner.symbol.clone(),
+            self.bids(),
+            self.asks(),
+            self.inner.timestamp,
+        )
+    }
+}
+
+#[pyclass(name = "Leg")]
+#[derive(Clone)]
+pub struct PyLeg {
+    inner: Leg,
+}
+
+impl From<Leg> for PyLeg {
+    fn from(inner: Leg) -> Self {
+        Self { inner }
+    }
+}
+
+#[pymethods]
+impl PyLeg {
+    #[new]
+    fn new(
+        from_asset: String,
+        to_asset: String,
+        exchange: String,
+        symbol: String,
+        side: &str,
+        rate: &PyAny,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            inner: Leg {
+                from_asset,
+                to_asset,
+                exchange,
+                symbol,
+                side: parse_side(side)?,
+                rate: py_decimal_to_rust(rate)?,
+            },
+        })
+    }
+
+    // Name is fixed by the `from_asset` Python attribute this getter
+    // exposes, not a Rust `From`-style conversion.
+    #[allow(clippy::wrong_self_convention)]
+    #[getter]
+    fn from_asset(&self) -> String {
+        self.inner.from_asset.clone()
+    }
+
+    #[getter]
+    fn to_asset(&self) -> String {
+        self.inner.to_asset.clone()
+    }
+
+    #[getter]
+    fn exchange(&self) -> String {
+        self.inner.exchange.clone()
+    }
+
+    #[getter]
+    fn symbol(&self) -> String {
+        self.inner.symbol.clone()
+    }
+
+    #[getter]
+    fn side(&self) -> &'static str {
+        side_name(self.inner.side)
+    }
+
+    #[getter]
+    fn rate(&self, py: Python<'_>) -> PyResult<PyObject> {
+        rust_decimal_to_py(py, self.inner.rate)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Leg(from_asset={:?}, to_asset={:?}, exchange={:?}, symbol={:?}, side={:?}, rate={})",
+            self.inner.from_asset,
+            self.inner.to_asset,
+            self.inner.exchange,
+            self.inner.symbol,
+            side_name(self.inner.side),
+            self.inner.rate
+        )
+    }
+
+    fn __getnewargs__(&self) -> (String, String, String, String, String, String) {
+        (
+            self.inner.from_asset.clone(),
+            self.inner.to_asset.clone(),
+            self.inner.exchange.clone(),
+            self.inner.symbol.clone(),
+            side_name(self.inner.side).to_string(),
+            self.inner.rate.to_string(),
+        )
+    }
+}
+
+#[pyclass(name = "ArbitrageOpportunity")]
+#[derive(Clone)]
+pub struct PyArbitrageOpportunity {
+    inner: ArbitrageOpportunity,
+}
+
+impl From<ArbitrageOpportunity> for PyArbitrageOpportunity {
+    fn from(inner: ArbitrageOpportunity) -> Self {
+        Self { inner }
+    }
+}
+
+#[pymethods]
+impl PyArbitrageOpportunity {
+    #[new]
+    fn new(legs: Vec<PyRef<PyLeg>>, gross_multiplier: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            inner: ArbitrageOpportunity {
+                legs: legs.iter().map(|leg| leg.inner.clone()).collect(),
+                gross_multiplier: py_decimal_to_rust(gross_multiplier)?,
+            },
+        })
+    }
+
+    #[getter]
+    fn legs(&self) -> Vec<PyLeg> {
+        self.inner
+            .legs
+            .iter()
+            .cloned()
+            .map(PyLeg::from)
+            .collect()
+    }
+
+    #[getter]
+    fn gross_multiplier(&self, py: Python<'_>) -> PyResult<PyObject> {
+        rust_decimal_to_py(py, self.inner.gross_multiplier)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ArbitrageOpportunity(legs={}, gross_multiplier={})",
+            self.inner.legs.len(),
+            self.inner.gross_multiplier
+        )
+    }
+
+    fn __getnewargs__(&self) -> (Vec<PyLeg>, String) {
+        (self.legs(), self.inner.gross_multiplier.to_string())
+    }
+}
+
+#[pyclass(name = "WalkResult")]
+#[derive(Clone)]
+pub struct PyWalkResult {
+    inner: WalkResult,
+}
+
+#[pymethods]
+impl PyWalkResult {
+    #[new]
+    fn new(filled_quantity: &PyAny, vwap: &PyAny, leftover_notional: &PyAny) -> PyResult<Self> {
+        Ok(Self {
+            inner: WalkResult {
+                filled_quantity: py_decimal_to_rust(filled_quantity)?,
+                vwap: py_decimal_to_rust(vwap)?,
+                leftover_notional: py_decimal_to_rust(leftover_notional)?,
+            },
+        })
+    }
+
+    #[getter]
+    fn filled_quantity(&self, py: Python<'_>) -> PyResult<PyObject> {
+        rust_decimal_to_py(py, self.inner.filled_quantity)
+    }
+
+    #[getter]
+    fn vwap(&self, py: Python<'_>) -> PyResult<PyObject> {
+        rust_decimal_to_py(py, self.inner.vwap)
+    }
+
+    #[getter]
+    fn leftover_notional(&self, py: Python<'_>) -> PyResult<PyObject> {
+        rust_decimal_to_py(py, self.inner.leftover_notional)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "WalkResult(filled_quantity={}, vwap={}, leftover_notional={})",
+            self.inner.filled_quantity, self.inner.vwap, self.inner.leftover_notional
+        )
+    }
+
+    fn __getnewargs__(&self) -> (String, String, String) {
+        (
+            self.inner.filled_quantity.to_string(),
+            self.inner.vwap.to_string(),
+            self.inner.leftover_notional.to_string(),
+        )
+    }
+}
+
+fn side_name(side: Side) -> &'static str {
+    match side {
+        Side::Buy => "buy",
+        Side::Sell => "sell",
+    }
+}
+
+fn parse_side(side: &str) -> PyResult<Side> {
+    match side {
+        "buy" => Ok(Side::Buy),
+        "sell" => Ok(Side::Sell),
+        other => Err(PyValueError::new_err(format!(
+            "unknown side {other:?}, expected \"buy\" or \"sell\""
+        ))),
+    }
+}
+
+fn parse_fill_side(side: &str) -> PyResult<FillSide> {
+    match side {
+        "buy" => Ok(FillSide::Buy),
+        "sell" => Ok(FillSide::Sell),
+        other => Err(PyValueError::new_err(format!(
+            "unknown side {other:?}, expected \"buy\" or \"sell\""
+        ))),
+    }
+}
+
+/// Python entry point for [`detect::detect_arbitrage`]. Each market is a
+/// `(exchange, base, quote, orderbook, fee_multiplier)` tuple; `fee_multiplier`
+/// is a `decimal.Decimal` (or anything `str()`-convertible to one).
+#[pyfunction]
+pub fn detect_arbitrage(
+    markets: Vec<(String, String, String, PyOrderbook, &PyAny)>,
+) -> PyResult<Option<PyArbitrageOpportunity>> {
+    let mut fee_multipliers = Vec::with_capacity(markets.len());
+    for (_, _, _, _, fee) in &markets {
+        fee_multipliers.push(py_decimal_to_rust(fee)?);
+    }
+
+    let rust_markets: Vec<Market> = markets
+        .iter()
+        .zip(fee_multipliers)
+        .map(|((exchange, base, quote, book, _), fee_multiplier)| Market {
+            base: base.clone(),
+            quote: quote.clone(),
+            exchange: exchange.clone(),
+            orderbook: &book.inner,
+            fee_multiplier,
+        })
+        .collect();
+
+    Ok(detect::detect_arbitrage(&rust_markets).map(PyArbitrageOpportunity::from))
+}
+
+/// Python entry point for [`Orderbook::walk_for_notional`]. `side` is
+/// `"buy"` or `"sell"`; `target_notional` and `max_avg_price` are
+/// `decimal.Decimal`-convertible.
+#[pyfunction]
+#[pyo3(signature = (orderbook, side, target_notional, max_avg_price=None))]
+pub fn walk_for_notional(
+    orderbook: &PyOrderbook,
+    side: &str,
+    target_notional: &PyAny,
+    max_avg_price: Option<&PyAny>,
+) -> PyResult<PyWalkResult> {
+    let side = parse_fill_side(side)?;
+    let target_notional = py_decimal_to_rust(target_notional)?;
+    let max_avg_price = max_avg_price.map(py_decimal_to_rust).transpose()?;
+    Ok(PyWalkResult {
+        inner: orderbook
+            .inner
+            .walk_for_notional(side, target_notional, max_avg_price),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn decimal_round_trips_through_python_via_string() {
+        Python::with_gil(|py| {
+            let original = Decimal::from_str("42000.125").unwrap();
+            let py_value = rust_decimal_to_py(py, original).unwrap();
+            let round_tripped = py_decimal_to_rust(py_value.as_ref(py)).unwrap();
+            assert_eq!(round_tripped, original);
+        });
+    }
+
+    #[test]
+    fn negative_and_zero_decimals_round_trip() {
+        Python::with_gil(|py| {
+            for raw in ["-1.50", "0", "0.000001"] {
+                let original = Decimal::from_str(raw).unwrap();
+                let py_value = rust_decimal_to_py(py, original).unwrap();
+                let round_tripped = py_decimal_to_rust(py_value.as_ref(py)).unwrap();
+                assert_eq!(round_tripped, original);
+            }
+        });
+    }
+
+    #[test]
+    fn py_decimal_to_rust_rejects_non_numeric_string() {
+        Python::with_gil(|py| {
+            let value: PyObject = "not-a-number".into_py(py);
+            assert!(py_decimal_to_rust(value.as_ref(py)).is_err());
+        });
+    }
+}
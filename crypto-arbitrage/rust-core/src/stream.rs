@@ -0,0 +1,290 @@
+//! Multi-exchange live orderbook aggregation.
+//!
+//! Maintains live books for many `(exchange, symbol)` pairs fed by
+//! [`ExchangeFeed`] implementations, one websocket connection per exchange
+//! subscribed to all of that exchange's symbols (rather than one connection
+//! per symbol). Incremental updates are merged into a cached snapshot; a
+//! sequence gap triggers a REST resync. Subscribers receive the best-N
+//! levels of a book every time it mutates, so the arbitrage detector can
+//! react without polling.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use thiserror::Error;
+use tokio::sync::{broadcast, RwLock};
+
+use crate::types::{Orderbook, PriceLevel};
+
+/// An incremental depth update for one `(exchange, symbol)` book. A
+/// zero-quantity level means "delete this price level".
+#[derive(Debug, Clone)]
+pub struct DepthUpdate {
+    pub exchange: String,
+    pub symbol: String,
+    /// Venue-assigned update sequence number, used to detect gaps.
+    pub sequence: u64,
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+    pub timestamp: u64,
+}
+
+/// A full book snapshot, used both for the initial load and for resync
+/// after a detected sequence gap.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub exchange: String,
+    pub symbol: String,
+    pub sequence: u64,
+    pub orderbook: Orderbook,
+}
+
+pub type UpdateSink = tokio::sync::mpsc::UnboundedSender<DepthUpdate>;
+
+/// Connects to one exchange's websocket feed and yields updates for all of
+/// its subscribed symbols over a single connection.
+#[async_trait::async_trait]
+pub trait ExchangeFeed: Send + Sync {
+    fn exchange(&self) -> &str;
+
+    /// Fetch a fresh REST snapshot, used on startup and after a gap.
+    async fn fetch_snapshot(&self, symbol: &str) -> Result<Snapshot, FeedError>;
+
+    /// Open one websocket connection subscribed to every symbol in
+    /// `symbols`, pushing updates to `sink` until the connection drops.
+    async fn run(&self, symbols: &[String], sink: UpdateSink) -> Result<(), FeedError>;
+}
+
+#[derive(Debug, Error)]
+pub enum FeedError {
+    #[error("connection error: {0}")]
+    Connection(String),
+    #[error("sequence gap on {exchange}/{symbol}: book at {book_seq}, update at {update_seq}")]
+    SequenceGap {
+        exchange: String,
+        symbol: String,
+        book_seq: u64,
+        update_seq: u64,
+    },
+}
+
+/// How many best levels per side to publish on every mutation, and how old
+/// (by the book's own `timestamp`) a book may get before [`Aggregator::evict_stale`]
+/// drops it.
+#[derive(Debug, Clone)]
+pub struct FeedConfig {
+    pub depth: usize,
+    pub staleness_threshold_ms: u64,
+}
+
+/// Best-N levels of one book, pushed to subscribers whenever it mutates.
+#[derive(Debug, Clone)]
+pub struct BookUpdate {
+    pub exchange: String,
+    pub symbol: String,
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+    pub timestamp: u64,
+}
+
+struct CachedBook {
+    book: Orderbook,
+    sequence: u64,
+}
+
+/// Maintains live books for many `(exchange, symbol)` pairs fed by one or
+/// more [`ExchangeFeed`]s, and republishes best-N levels on every mutation.
+pub struct Aggregator {
+    config: FeedConfig,
+    books: Arc<RwLock<HashMap<(String, String), CachedBook>>>,
+    publisher: broadcast::Sender<BookUpdate>,
+}
+
+impl Aggregator {
+    pub fn new(config: FeedConfig) -> Self {
+        let (publisher, _) = broadcast::channel(1024);
+        Self {
+            config,
+            books: Arc::new(RwLock::new(HashMap::new())),
+            publisher,
+        }
+    }
+
+    /// Subscribe to best-N level updates for every book this aggregator tracks.
+    pub fn subscribe(&self) -> broadcast::Receiver<BookUpdate> {
+        self.publisher.subscribe()
+    }
+
+    /// Run one feed end to end: fetch the initial snapshot for each symbol,
+    /// then consume its single websocket connection, resyncing via a fresh
+    /// snapshot whenever a sequence gap is detected, until the connection
+    /// closes for good.
+    pub async fn run_feed(
+        &self,
+        feed: Arc<dyn ExchangeFeed>,
+        symbols: Vec<String>,
+    ) -> Result<(), FeedError> {
+        for symbol in &symbols {
+            let snapshot = feed.fetch_snapshot(symbol).await?;
+            self.apply_snapshot(snapshot).await;
+        }
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let feed_for_task = feed.clone();
+        let run_handle = tokio::spawn(async move { feed_for_task.run(&symbols, tx).await });
+
+        while let Some(update) = rx.recv().await {
+            let symbol = update.symbol.clone();
+            if let Err(FeedError::SequenceGap { .. }) = self.apply_update(update).await {
+                let snapshot = feed.fetch_snapshot(&symbol).await?;
+                self.apply_snapshot(snapshot).await;
+            }
+        }
+
+        run_handle
+            .await
+            .map_err(|e| FeedError::Connection(e.to_string()))?
+    }
+
+    async fn apply_snapshot(&self, snapshot: Snapshot) {
+        let key = (snapshot.exchange.clone(), snapshot.symbol.clone());
+        {
+            let mut books = self.books.write().await;
+            books.insert(
+                key,
+                CachedBook {
+                    book: snapshot.orderbook,
+                    sequence: snapshot.sequence,
+                },
+            );
+        }
+        self.publish(&snapshot.exchange, &snapshot.symbol).await;
+    }
+
+    /// Merge one incremental update into its cached book: upsert each level
+    /// by price, drop zero-quantity levels, keep bids sorted descending and
+    /// asks ascending. An update whose `sequence` is at or below the cached
+    /// sequence is stale or a duplicate: it is ignored and `Ok(())` is
+    /// returned. Returns `Err(SequenceGap)` without applying the update if
+    /// `update.sequence` skips past the cached sequence + 1 (including when
+    /// no book is cached yet).
+    async fn apply_update(&self, update: DepthUpdate) -> Result<(), FeedError> {
+        let key = (update.exchange.clone(), update.symbol.clone());
+        {
+            let mut books = self.books.write().await;
+            let Some(cached) = books.get_mut(&key) else {
+                return Err(FeedError::SequenceGap {
+                    exchange: update.exchange,
+                    symbol: update.symbol,
+                    book_seq: 0,
+                    update_seq: update.sequence,
+                });
+            };
+            if update.sequence <= cached.sequence {
+                return Ok(()); // stale/duplicate update
+            }
+            if update.sequence != cached.sequence + 1 {
+                return Err(FeedError::SequenceGap {
+                    exchange: update.exchange.clone(),
+                    symbol: update.symbol.clone(),
+                    book_seq: cached.sequence,
+                    update_seq: update.sequence,
+                });
+            }
+            merge_levels(&mut cached.book.bids, &update.bids, true);
+            merge_levels(&mut cached.book.asks, &update.asks, false);
+            cached.book.timestamp = update.timestamp;
+            cached.sequence = update.sequence;
+        }
+        self.publish(&update.exchange, &update.symbol).await;
+        Ok(())
+    }
+
+    async fn publish(&self, exchange: &str, symbol: &str) {
+        let books = self.books.read().await;
+        let Some(cached) = books.get(&(exchange.to_string(), symbol.to_string())) else {
+            return;
+        };
+        let depth = self.config.depth;
+        let _ = self.publisher.send(BookUpdate {
+            exchange: exchange.to_string(),
+            symbol: symbol.to_string(),
+            bids: cached.book.bids.iter().take(depth).cloned().collect(),
+            asks: cached.book.asks.iter().take(depth).cloned().collect(),
+            timestamp: cached.book.timestamp,
+        });
+    }
+
+    /// Discard every cached book whose `timestamp` is older than
+    /// `config.staleness_threshold_ms` relative to `now_ms`.
+    pub async fn evict_stale(&self, now_ms: u64) {
+        let threshold = self.config.staleness_threshold_ms;
+        let mut books = self.books.write().await;
+        books.retain(|_, cached| now_ms.saturating_sub(cached.book.timestamp) <= threshold);
+    }
+}
+
+/// Apply incremental `updates` onto `levels`: replace-by-price, drop
+/// zero-quantity levels, then re-sort (descending for bids, ascending for
+/// asks).
+fn merge_levels(levels: &mut Vec<PriceLevel>, updates: &[PriceLevel], descending: bool) {
+    for update in updates {
+        levels.retain(|level| level.price != update.price);
+        if !update.quantity.is_zero() {
+            levels.push(update.clone());
+        }
+    }
+    if descending {
+        levels.sort_by_key(|level| std::cmp::Reverse(level.price));
+    } else {
+        levels.sort_by_key(|level| level.price);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use rust_decimal::Decimal;
+
+    fn level(price: &str, quantity: &str) -> PriceLevel {
+        PriceLevel {
+            price: Decimal::from_str(price).unwrap(),
+            quantity: Decimal::from_str(quantity).unwrap(),
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn upserts_by_price_and_sorts_bids_descending() {
+        let mut bids = vec![level("100", "1"), level("99", "1")];
+        let updates = vec![level("100", "2"), level("101", "1")];
+
+        merge_levels(&mut bids, &updates, true);
+
+        assert_eq!(
+            bids,
+            vec![level("101", "1"), level("100", "2"), level("99", "1")]
+        );
+    }
+
+    #[test]
+    fn sorts_asks_ascending() {
+        let mut asks = vec![level("101", "1"), level("100", "1")];
+        let updates = vec![level("99", "1")];
+
+        merge_levels(&mut asks, &updates, false);
+
+        assert_eq!(asks, vec![level("99", "1"), level("100", "1"), level("101", "1")]);
+    }
+
+    #[test]
+    fn zero_quantity_update_deletes_the_level() {
+        let mut bids = vec![level("100", "1"), level("99", "1")];
+        let updates = vec![level("99", "0")];
+
+        merge_levels(&mut bids, &updates, true);
+
+        assert_eq!(bids, vec![level("100", "1")]);
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use rust_decimal::Decimal;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PriceLevel {
     pub price: Decimal,
     pub quantity: Decimal,
@@ -16,3 +16,23 @@ pub struct Orderbook {
     pub asks: Vec<PriceLevel>,
     pub timestamp: u64,
 }
+
+/// Per-symbol trading rules for a venue, mirroring an exchange-info endpoint
+/// (e.g. Binance's `/exchangeInfo`). Used to snap prices/quantities to the
+/// precision and bounds the venue will actually accept.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeRules {
+    pub exchange: String,
+    pub symbol: String,
+    /// Number of decimal places the venue accepts for price.
+    pub price_scale: u32,
+    /// Number of decimal places the venue accepts for quantity.
+    pub quantity_scale: u32,
+    /// Prices must be an integer multiple of this.
+    pub tick_size: Decimal,
+    /// Quantities must be an integer multiple of this.
+    pub step_size: Decimal,
+    pub min_notional: Decimal,
+    pub min_qty: Decimal,
+    pub max_qty: Decimal,
+}